@@ -23,13 +23,95 @@ use wasm_bindgen_futures::JsFuture;
 use web_sys::{Request, RequestInit, RequestMode, WorkerGlobalScope};
 use md5::Md5;                    // MD5 streaming hash computation
 use sha2::{Sha256, Digest};      // SHA256 digest calculation (required for S3 V4 signing)
+use sha1::Sha1;                  // SHA1 digest, for the SHA1 additive checksum
 use hmac::{Hmac, Mac};           // HMAC message authentication code (required for S3 V4 signing)
 use js_sys::{Uint8Array, Date, encode_uri_component};  // JavaScript interop types
 use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};  // base64 for checksum headers
 
 // Type alias for HMAC-SHA256, used in S3 V4 signature algorithm
 type HmacSha256 = Hmac<Sha256>;
 
+// SHA256 of an empty string, reused everywhere S3 V4 signing needs the
+// "no body" payload hash (GET/DELETE requests, and the per-chunk
+// string-to-sign in chunked streaming uploads).
+const EMPTY_SHA256_HEX: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+// Literal payload-hash value that tells S3 the body is framed using the
+// aws-chunked / STREAMING-AWS4-HMAC-SHA256-PAYLOAD encoding instead of a
+// single upfront SHA256 digest.
+const STREAMING_PAYLOAD_HASH: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD";
+
+// Literal payload-hash value that opts out of per-request body hashing.
+// Safe to use over HTTPS, where TLS already protects payload integrity.
+const UNSIGNED_PAYLOAD_HASH: &str = "UNSIGNED-PAYLOAD";
+
+// Size of each signed sub-chunk inside a streaming-signed request body.
+// 64 KiB keeps per-chunk signature overhead small while still hashing
+// incrementally instead of over the whole part at once.
+const STREAMING_CHUNK_SIZE: usize = 64 * 1024;
+
+// ============================================================================
+// AWS-Spec URI Encoding
+// ============================================================================
+// Percent-encodes every byte except the unreserved set `A-Za-z0-9-_.~`,
+// using uppercase hex, exactly as the SigV4 spec requires for canonical
+// URIs. `encode_slash` controls whether `/` is also escaped (`true` for a
+// single path segment such as an object key component, `false` to keep `/`
+// as a path separator when encoding a whole canonical URI at once).
+//
+// `js_sys::encode_uri_component` (used elsewhere in this file for query
+// values) does not match this spec exactly - e.g. it leaves `!'()*`
+// unescaped - so canonical-URI construction needs this instead.
+// ============================================================================
+fn uri_encode(input: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod uri_encode_tests {
+    use super::uri_encode;
+
+    #[test]
+    fn leaves_unreserved_characters_untouched() {
+        assert_eq!(uri_encode("abcXYZ019-_.~", false), "abcXYZ019-_.~");
+    }
+
+    #[test]
+    fn percent_encodes_with_uppercase_hex() {
+        assert_eq!(uri_encode("a b", false), "a%20b");
+        assert_eq!(uri_encode("é", false), "%C3%A9");
+    }
+
+    #[test]
+    fn keeps_slash_as_separator_unless_encode_slash_is_set() {
+        assert_eq!(uri_encode("a/b", false), "a/b");
+        assert_eq!(uri_encode("a/b", true), "a%2Fb");
+    }
+}
+
+// S3's additive-checksum header name for a given algorithm, e.g. "CRC32C" ->
+// "x-amz-checksum-crc32c". Used for both `upload_part`'s request header and
+// the matching `<Checksum*>` completion-XML element (via `checksum_xml_tag`).
+fn checksum_header_name(algorithm: &str) -> String {
+    format!("x-amz-checksum-{}", algorithm.to_lowercase())
+}
+
+// S3 completion-XML element name for a given algorithm, e.g. "sha256" ->
+// "ChecksumSHA256".
+fn checksum_xml_tag(algorithm: &str) -> String {
+    format!("Checksum{}", algorithm.to_uppercase())
+}
+
 // ============================================================================
 // Initialize Panic Hook: Display Rust panic messages in browser console
 // ============================================================================
@@ -65,33 +147,39 @@ pub fn init_panic_hook() {
 #[wasm_bindgen]
 pub struct IncrementalHasher {
     sha256: Sha256,
+    sha1: Sha1,
     md5_ctx: Md5,
+    crc32c: u32,
+    crc32: crc32fast::Hasher,
 }
 
 #[wasm_bindgen]
 impl IncrementalHasher {
     /// Create a new streaming hash calculator
-    /// 
+    ///
     /// Initializes both SHA256 and MD5 hash contexts.
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
         Self {
             sha256: Sha256::new(),
+            sha1: Sha1::new(),
             md5_ctx: Md5::new(),
+            crc32c: 0,
+            crc32: crc32fast::Hasher::new(),
         }
     }
 
     /// Update hash state with a new data chunk
-    /// 
+    ///
     /// Parameters:
     /// - chunk: JavaScript Uint8Array containing the data to hash
-    /// 
+    ///
     /// Notes:
     /// - Performs one memory copy from JS to Rust heap
     /// - For ~1MB chunks, the performance overhead is negligible
     /// - Safer than direct JS memory access, avoids lifetime issues
     /// - Can be called multiple times with different chunks
-    /// 
+    ///
     /// Example usage from JavaScript:
     /// ```js
     /// const hasher = new IncrementalHasher();
@@ -104,17 +192,20 @@ impl IncrementalHasher {
         // Copy JS Uint8Array to Rust Vec
         let mut buffer = vec![0u8; chunk.length() as usize];
         chunk.copy_to(&mut buffer);
-        
-        // Update both SHA256 and MD5 state
+
+        // Update SHA256, SHA1, MD5, CRC32C and CRC32 state
         self.sha256.update(&buffer);
+        self.sha1.update(&buffer);
         self.md5_ctx.update(&buffer);
+        self.crc32c = crc32c::crc32c_append(self.crc32c, &buffer);
+        self.crc32.update(&buffer);
     }
 
     /// Finalize SHA256 computation and return hexadecimal string
-    /// 
+    ///
     /// Returns:
     /// - SHA256 hash as lowercase hexadecimal string (64 characters)
-    /// 
+    ///
     /// Notes:
     /// - Clones internal state, so this method can be called multiple times
     /// - Does not consume the hasher, allowing continued updates
@@ -123,19 +214,491 @@ impl IncrementalHasher {
     }
 
     /// Finalize MD5 computation and return hexadecimal string
-    /// 
+    ///
     /// Returns:
     /// - MD5 hash as lowercase hexadecimal string (32 characters)
-    /// 
+    ///
     /// Notes:
     /// - Clones internal state, so this method can be called multiple times
     /// - Does not consume the hasher, allowing continued updates
     pub fn finalize_md5(&self) -> String {
         format!("{:x}", self.md5_ctx.clone().finalize())
     }
+
+    /// Finalize CRC32C computation and return it base64-encoded
+    ///
+    /// Returns:
+    /// - CRC32C checksum, big-endian, base64-encoded (the form S3 expects in
+    ///   the `x-amz-checksum-crc32c` header and `<ChecksumCRC32C>` element)
+    ///
+    /// Notes:
+    /// - Idempotent: can be called multiple times without resetting state
+    pub fn finalize_crc32c(&self) -> String {
+        BASE64.encode(self.crc32c.to_be_bytes())
+    }
+
+    /// Finalize one of S3's additive-checksum algorithms ("CRC32", "CRC32C",
+    /// "SHA1" or "SHA256", case-insensitive) and return it base64-encoded -
+    /// the form `x-amz-checksum-*` headers and `<Checksum*>` completion-XML
+    /// elements expect. This is the entry point `upload_part`/
+    /// `upload_part_streaming`'s `checksum_algorithm` parameter is meant to
+    /// be paired with.
+    ///
+    /// Notes:
+    /// - Idempotent: can be called multiple times without resetting state
+    pub fn finalize_checksum(&self, algorithm: &str) -> Result<String, JsValue> {
+        match algorithm.to_uppercase().as_str() {
+            "CRC32" => Ok(BASE64.encode(self.crc32.clone().finalize().to_be_bytes())),
+            "CRC32C" => Ok(self.finalize_crc32c()),
+            "SHA1" => Ok(BASE64.encode(self.sha1.clone().finalize())),
+            "SHA256" => Ok(BASE64.encode(self.sha256.clone().finalize())),
+            other => Err(JsValue::from_str(&format!("unsupported checksum algorithm: {}", other))),
+        }
+    }
+}
+
+// ============================================================================
+// PutMultipartOpts: Optional Metadata for Multipart Upload Sessions
+// ============================================================================
+// Mirrors `PutMultipartOpts`/`Attributes` from object_store: a small bag of
+// optional upload-initiation metadata (Content-Type/Encoding, Cache-Control,
+// arbitrary user metadata, and an object tag set) that would otherwise blow
+// up `initiate_multipart_upload`'s parameter list.
+//
+// `user_metadata` is a "key1=val1,key2=val2" string - each pair becomes an
+// `x-amz-meta-<key>` header. `tagging` is S3's own
+// "key1=val1&key2=val2" URL-encoded tag-set format, sent as `x-amz-tagging`.
+// ============================================================================
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct PutMultipartOpts {
+    content_type: Option<String>,
+    content_encoding: Option<String>,
+    cache_control: Option<String>,
+    user_metadata: Option<String>,
+    tagging: Option<String>,
+}
+
+#[wasm_bindgen]
+impl PutMultipartOpts {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_content_type(&mut self, value: String) {
+        self.content_type = Some(value);
+    }
+
+    pub fn set_content_encoding(&mut self, value: String) {
+        self.content_encoding = Some(value);
+    }
+
+    pub fn set_cache_control(&mut self, value: String) {
+        self.cache_control = Some(value);
+    }
+
+    /// `value` format: "key1=val1,key2=val2"
+    pub fn set_user_metadata(&mut self, value: String) {
+        self.user_metadata = Some(value);
+    }
+
+    /// `value` format: S3's own tag-set format, "key1=val1&key2=val2" (URL-encoded)
+    pub fn set_tagging(&mut self, value: String) {
+        self.tagging = Some(value);
+    }
+}
+
+impl PutMultipartOpts {
+    // Flattens this options bag into (header-name, value) pairs ready to be
+    // folded into a canonical-headers / SignedHeaders set.
+    fn header_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        if let Some(v) = &self.content_type {
+            pairs.push(("content-type".to_string(), v.clone()));
+        }
+        if let Some(v) = &self.content_encoding {
+            pairs.push(("content-encoding".to_string(), v.clone()));
+        }
+        if let Some(v) = &self.cache_control {
+            pairs.push(("cache-control".to_string(), v.clone()));
+        }
+        if let Some(v) = &self.tagging {
+            pairs.push(("x-amz-tagging".to_string(), v.clone()));
+        }
+        if let Some(metadata) = &self.user_metadata {
+            for (key, value) in parse_kv_pairs(metadata) {
+                pairs.push((format!("x-amz-meta-{}", key), value));
+            }
+        }
+        pairs
+    }
+}
+
+// Parses a "key1=val1,key2=val2" string into trimmed (key, value) pairs,
+// skipping malformed or empty-key entries. Keys are lowercased since they
+// feed into SigV4 canonical header names, which must be lowercase to match
+// S3's server-side canonicalization - an `x-amz-meta-UserId` header signed
+// with a mixed-case key would never match S3's `x-amz-meta-userid`.
+fn parse_kv_pairs(s: &str) -> Vec<(String, String)> {
+    s.split(',')
+        .filter_map(|pair| {
+            let mut kv = pair.splitn(2, '=');
+            let key = kv.next()?.trim();
+            let value = kv.next()?.trim();
+            if key.is_empty() { None } else { Some((key.to_lowercase(), value.to_string())) }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod parse_kv_pairs_tests {
+    use super::parse_kv_pairs;
+
+    #[test]
+    fn lowercases_keys_for_sigv4_header_names() {
+        let pairs = parse_kv_pairs("UserId=42,plain=1");
+        assert_eq!(pairs, vec![("userid".to_string(), "42".to_string()), ("plain".to_string(), "1".to_string())]);
+    }
+}
+
+// ============================================================================
+// EncryptionConfig: Server-Side Encryption Headers
+// ============================================================================
+// Selects one of S3's three server-side-encryption modes and carries the
+// headers needed to request it:
+// - SSE-S3: `x-amz-server-side-encryption: AES256`, server-managed keys.
+// - SSE-KMS: `x-amz-server-side-encryption: aws:kms`, optionally pinned to a
+//   CMK via `x-amz-server-side-encryption-aws-kms-key-id`.
+// - SSE-C: caller-supplied key, sent as `x-amz-server-side-encryption-
+//   customer-algorithm`/`-customer-key`/`-customer-key-md5`.
+//
+// `initiate_multipart_upload` sends every header set here (SSE-C must be
+// present on CreateMultipartUpload too); `upload_part`/`upload_part_streaming`
+// replay only the SSE-C headers, since S3 needs the same key on every part to
+// decrypt-then-re-encrypt it.
+// ============================================================================
+#[wasm_bindgen]
+#[derive(Default, Clone)]
+pub struct EncryptionConfig {
+    sse_algorithm: Option<String>,
+    kms_key_id: Option<String>,
+    customer_algorithm: Option<String>,
+    customer_key: Option<String>,
+    customer_key_md5: Option<String>,
+}
+
+#[wasm_bindgen]
+impl EncryptionConfig {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// SSE-S3: server-managed keys. Mutually exclusive with `set_sse_kms`/
+    /// `set_sse_c` - whichever is called last wins.
+    pub fn set_sse_s3(&mut self) {
+        self.clear();
+        self.sse_algorithm = Some("AES256".to_string());
+    }
+
+    /// SSE-KMS, optionally pinned to `key_id` (a CMK ID or ARN). `None` lets
+    /// the bucket's default CMK apply. Mutually exclusive with `set_sse_s3`/
+    /// `set_sse_c` - whichever is called last wins.
+    pub fn set_sse_kms(&mut self, key_id: Option<String>) {
+        self.clear();
+        self.sse_algorithm = Some("aws:kms".to_string());
+        self.kms_key_id = key_id;
+    }
+
+    /// SSE-C: `key_base64` is the raw 256-bit key, base64-encoded;
+    /// `key_md5_base64` is the base64-encoded MD5 digest of that raw
+    /// (not base64-encoded) key. Both are required by S3 on every request.
+    /// Mutually exclusive with `set_sse_s3`/`set_sse_kms` - whichever is
+    /// called last wins.
+    pub fn set_sse_c(&mut self, key_base64: String, key_md5_base64: String) {
+        self.clear();
+        self.customer_algorithm = Some("AES256".to_string());
+        self.customer_key = Some(key_base64);
+        self.customer_key_md5 = Some(key_md5_base64);
+    }
+
+    // Resets every field, so switching modes on a reused config never leaves
+    // headers from a previous, incompatible mode behind.
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl EncryptionConfig {
+    // Full header set, for `initiate_multipart_upload`.
+    fn header_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        if let Some(v) = &self.sse_algorithm {
+            pairs.push(("x-amz-server-side-encryption".to_string(), v.clone()));
+        }
+        if let Some(v) = &self.kms_key_id {
+            pairs.push(("x-amz-server-side-encryption-aws-kms-key-id".to_string(), v.clone()));
+        }
+        pairs.extend(self.sse_c_header_pairs());
+        pairs
+    }
+
+    // SSE-C headers alone, for `upload_part`/`upload_part_streaming`, which
+    // must replay them on every part.
+    fn sse_c_header_pairs(&self) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        if let Some(v) = &self.customer_algorithm {
+            pairs.push(("x-amz-server-side-encryption-customer-algorithm".to_string(), v.clone()));
+        }
+        if let Some(v) = &self.customer_key {
+            pairs.push(("x-amz-server-side-encryption-customer-key".to_string(), v.clone()));
+        }
+        if let Some(v) = &self.customer_key_md5 {
+            pairs.push(("x-amz-server-side-encryption-customer-key-md5".to_string(), v.clone()));
+        }
+        pairs
+    }
+}
+
+// ============================================================================
+// Retry Policy
+// ============================================================================
+// Governs how `upload_part`, `upload_part_streaming`, `initiate_multipart_upload`,
+// `complete_multipart_upload` and `abort_multipart_upload` react to transient
+// failures: network errors,
+// HTTP 5xx/429, and a timed-out attempt are retried with exponential backoff
+// plus jitter, up to `max_attempts`. `on_error` controls what happens once a
+// part upload has failed for good - attempts exhausted, or an error response
+// that is not retried - `AbortUpload` automatically calls
+// `abort_multipart_upload` so the session doesn't leak storage. This never
+// fires for a caller-initiated cancellation (`signal` aborted mid-part).
+// ============================================================================
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OnError {
+    DoNothing,
+    AbortUpload,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct RetryConfig {
+    max_attempts: u32,
+    base_backoff_ms: u32,
+    max_backoff_ms: u32,
+    on_error: OnError,
+    create_timeout_ms: u32,
+    part_timeout_ms: u32,
+    abort_timeout_ms: u32,
+    complete_timeout_ms: u32,
+}
+
+#[wasm_bindgen]
+impl RetryConfig {
+    /// Defaults: single attempt (no retries), `DoNothing`, and generous
+    /// per-request-type timeouts - callers opt into retrying by raising
+    /// `max_attempts`.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 1,
+            base_backoff_ms: 200,
+            max_backoff_ms: 5_000,
+            on_error: OnError::DoNothing,
+            create_timeout_ms: 15_000,
+            part_timeout_ms: 60_000,
+            abort_timeout_ms: 15_000,
+            complete_timeout_ms: 30_000,
+        }
+    }
+
+    pub fn set_max_attempts(&mut self, value: u32) {
+        self.max_attempts = value.max(1);
+    }
+
+    pub fn set_base_backoff_ms(&mut self, value: u32) {
+        self.base_backoff_ms = value;
+    }
+
+    pub fn set_max_backoff_ms(&mut self, value: u32) {
+        self.max_backoff_ms = value;
+    }
+
+    pub fn set_on_error(&mut self, value: OnError) {
+        self.on_error = value;
+    }
+
+    pub fn set_create_timeout_ms(&mut self, value: u32) {
+        self.create_timeout_ms = value;
+    }
+
+    pub fn set_part_timeout_ms(&mut self, value: u32) {
+        self.part_timeout_ms = value;
+    }
+
+    pub fn set_abort_timeout_ms(&mut self, value: u32) {
+        self.abort_timeout_ms = value;
+    }
+
+    pub fn set_complete_timeout_ms(&mut self, value: u32) {
+        self.complete_timeout_ms = value;
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Structured S3 Error
+// ============================================================================
+// S3/MinIO/Ceph failures come back as an XML `<Error>` document with `<Code>`,
+// `<Message>`, `<RequestId>` and `<Resource>`. `S3Error` parses that body and
+// maps the `Code` onto `S3ErrorCode` so callers can branch on it (retry on
+// `SlowDown`, re-initiate on `NoSuchUpload`, ...) instead of string-matching
+// the raw error text.
+// ============================================================================
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum S3ErrorCode {
+    NoSuchUpload,
+    NoSuchBucket,
+    NoSuchKey,
+    EntityTooSmall,
+    EntityTooLarge,
+    AccessDenied,
+    InvalidAccessKeyId,
+    SignatureDoesNotMatch,
+    SlowDown,
+    RequestTimeout,
+    InternalError,
+    ServiceUnavailable,
+    Unknown,
+}
+
+impl S3ErrorCode {
+    fn from_raw(raw_code: &str) -> Self {
+        match raw_code {
+            "NoSuchUpload" => Self::NoSuchUpload,
+            "NoSuchBucket" => Self::NoSuchBucket,
+            "NoSuchKey" => Self::NoSuchKey,
+            "EntityTooSmall" => Self::EntityTooSmall,
+            "EntityTooLarge" => Self::EntityTooLarge,
+            "AccessDenied" => Self::AccessDenied,
+            "InvalidAccessKeyId" => Self::InvalidAccessKeyId,
+            "SignatureDoesNotMatch" => Self::SignatureDoesNotMatch,
+            "SlowDown" => Self::SlowDown,
+            "RequestTimeout" => Self::RequestTimeout,
+            "InternalError" => Self::InternalError,
+            "ServiceUnavailable" => Self::ServiceUnavailable,
+            _ => Self::Unknown,
+        }
+    }
 }
 
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct S3Error {
+    code: S3ErrorCode,
+    raw_code: String,
+    message: String,
+    request_id: String,
+    resource: String,
+    status: u16,
+}
+
+#[wasm_bindgen]
+impl S3Error {
+    pub fn code(&self) -> S3ErrorCode {
+        self.code
+    }
+
+    pub fn raw_code(&self) -> String {
+        self.raw_code.clone()
+    }
 
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    pub fn request_id(&self) -> String {
+        self.request_id.clone()
+    }
+
+    pub fn resource(&self) -> String {
+        self.resource.clone()
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Human-readable rendering, for contexts that just log/display the error.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_display_string(&self) -> String {
+        format!("{} ({}): {}", self.raw_code, self.status, self.message)
+    }
+}
+
+impl S3Error {
+    // Parses an S3 XML `<Error>` document. Falls back to an `Unknown`-coded
+    // wrapper around the raw body when it isn't a recognizable S3 error
+    // document (e.g. an upstream proxy's HTML error page).
+    fn parse(status: u16, body: &str) -> Self {
+        let raw_code = Uploader::extract_xml_tag(body, "Code").unwrap_or_else(|| "Unknown".to_string());
+        let message = Uploader::extract_xml_tag(body, "Message").unwrap_or_else(|| body.to_string());
+        let request_id = Uploader::extract_xml_tag(body, "RequestId").unwrap_or_default();
+        let resource = Uploader::extract_xml_tag(body, "Resource").unwrap_or_default();
+        Self {
+            code: S3ErrorCode::from_raw(&raw_code),
+            raw_code,
+            message,
+            request_id,
+            resource,
+            status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod s3_error_parse_tests {
+    use super::{S3Error, S3ErrorCode};
+
+    #[test]
+    fn maps_a_recognized_error_document_to_its_code() {
+        let body = "<Error><Code>NoSuchUpload</Code><Message>Upload not found</Message>\
+            <RequestId>req-1</RequestId><Resource>/bucket/key</Resource></Error>";
+        let err = S3Error::parse(404, body);
+        assert_eq!(err.code, S3ErrorCode::NoSuchUpload);
+        assert_eq!(err.raw_code, "NoSuchUpload");
+        assert_eq!(err.message, "Upload not found");
+        assert_eq!(err.request_id, "req-1");
+        assert_eq!(err.resource, "/bucket/key");
+        assert_eq!(err.status, 404);
+    }
+
+    #[test]
+    fn maps_an_unrecognized_code_to_unknown() {
+        let body = "<Error><Code>SomeFutureCode</Code><Message>huh</Message></Error>";
+        let err = S3Error::parse(400, body);
+        assert_eq!(err.code, S3ErrorCode::Unknown);
+        assert_eq!(err.raw_code, "SomeFutureCode");
+    }
+
+    #[test]
+    fn falls_back_to_the_raw_body_when_not_an_s3_error_document() {
+        let body = "<html>502 Bad Gateway</html>";
+        let err = S3Error::parse(502, body);
+        assert_eq!(err.code, S3ErrorCode::Unknown);
+        assert_eq!(err.raw_code, "Unknown");
+        assert_eq!(err.message, body);
+        assert_eq!(err.request_id, "");
+        assert_eq!(err.resource, "");
+    }
+}
 
 // ============================================================================
 // Uploader: S3/MinIO Upload Client
@@ -164,6 +727,11 @@ pub struct Uploader {
     session_token: String, // STS Session Token (required for temporary credentials)
     region: String,        // Bucket region (e.g., "us-east-1", "cn-north-1")
     endpoint: String,      // Service endpoint (e.g., "http://192.168.1.10:9000", "https://s3.amazonaws.com")
+    // Cache of (datestamp, kSigning) from the last key derivation. S3 V4 keys
+    // are valid for the whole UTC day they were derived for, so parallel part
+    // uploads firing within the same day can skip re-running all four HMAC
+    // steps. Interior mutability because signing happens through `&self`.
+    signing_key_cache: std::cell::RefCell<Option<(String, Vec<u8>)>>,
 }
 
 #[wasm_bindgen]
@@ -204,11 +772,31 @@ impl Uploader {
             session_token: token,
             region,
             endpoint,
+            signing_key_cache: std::cell::RefCell::new(None),
         }
     }
 
     /// 执行分片上传（UploadPart 操作）
     /// 此方法为“黑盒”核心，内部完成：数据 SHA256 计算 -> S3 V4 签名 -> 网络请求
+    ///
+    /// `unsigned_payload`: 为 true 时使用 `UNSIGNED-PAYLOAD` 字面量代替真实的
+    /// `Sha256::digest(&chunk_data)`，省去一次完整分片的哈希扫描。仅适用于 HTTPS
+    /// 端点 —— TLS 已经提供传输完整性，payload hash 带来的额外校验收益很小。
+    ///
+    /// `checksum_algorithm`/`checksum_value`: an optional additive checksum -
+    /// algorithm one of "CRC32", "CRC32C", "SHA1", "SHA256", and `checksum_value`
+    /// its base64 digest (from `IncrementalHasher::finalize_checksum`). Sent as
+    /// the matching `x-amz-checksum-<algorithm>` header (added to SignedHeaders).
+    /// The server verifies and echoes it back; pair it with `complete_multipart_upload`'s
+    /// `checksum_algorithm`/`part_checksums` so the completed object gets a
+    /// `<Checksum*>` element too - a more reliable end-to-end integrity check
+    /// than the ETag (which, in the multipart case, isn't a whole-object MD5).
+    ///
+    /// `encryption`: when the session was started with `EncryptionConfig::set_sse_c`,
+    /// the same config must be passed here too - S3 needs the customer key
+    /// replayed on every part to decrypt-then-re-encrypt it. SSE-S3/SSE-KMS
+    /// headers aren't needed here (set once at `initiate_multipart_upload`), so
+    /// passing an SSE-S3/SSE-KMS-only `EncryptionConfig` is a no-op.
     pub async fn upload_part(
         &self,
         bucket: String,
@@ -217,10 +805,24 @@ impl Uploader {
         part_number: u32,
         chunk: Uint8Array,
         signal: &JsValue,
+        unsigned_payload: bool,
+        checksum_algorithm: Option<String>,
+        checksum_value: Option<String>,
+        encryption: Option<EncryptionConfig>,
+        retry_config: Option<RetryConfig>,
     ) -> Result<String, JsValue> {
         // CRITICAL: Immediately copy JS data to Rust memory to avoid accessing
         // invalidated JS pointers after async await points
         let chunk_data = chunk.to_vec();
+        let retry_config = retry_config.unwrap_or_default();
+
+        // (header-name, value) for the optional additive checksum, computed
+        // once since both fields must be present together.
+        let checksum_header = match (&checksum_algorithm, &checksum_value) {
+            (Some(algorithm), Some(value)) => Some((checksum_header_name(algorithm), value.clone())),
+            _ => None,
+        };
+        let sse_c_headers = encryption.as_ref().map(|e| e.sse_c_header_pairs()).unwrap_or_default();
 
         let method = "PUT";
 
@@ -232,76 +834,453 @@ impl Uploader {
         // S3 V4 requires query parameters in alphabetical order: partNumber before uploadId
         let query = format!("partNumber={}&uploadId={}", part_number, encoded_upload_id);
 
+        let host = self.endpoint.replace("http://", "").replace("https://", "");
+
+        // Calculate SHA256 hash of the payload, unless the caller opted into
+        // UNSIGNED-PAYLOAD (skips the full-buffer digest entirely).
+        let content_sha256 = if unsigned_payload {
+            UNSIGNED_PAYLOAD_HASH.to_string()
+        } else {
+            hex::encode(Sha256::digest(&chunk_data))
+        };
+
+        // Construct canonical URI - must start with /
+        // Handle object_key that may already have leading slash to prevent //
+        let clean_object_key = object_key.trim_start_matches('/');
+        let canonical_uri = uri_encode(&format!("/{}/{}", bucket, clean_object_key), false);
+
+        // Re-signs and rebuilds the request on every attempt, since the
+        // signature is bound to a fresh `x-amz-date` each time.
+        let build_request = || -> Result<Request, JsValue> {
+            let amz_date = self.get_amz_date();
+            let datestamp = &amz_date[..8];
+
+            // Construct canonical headers (order matters for signature). The checksum
+            // header is optional, so the signed-header set is built dynamically and
+            // sorted alphabetically rather than hardcoded like the fixed-header calls.
+            let mut header_pairs: Vec<(String, String)> = vec![
+                ("host".to_string(), host.clone()),
+                ("x-amz-content-sha256".to_string(), content_sha256.clone()),
+                ("x-amz-date".to_string(), amz_date.clone()),
+                ("x-amz-security-token".to_string(), self.session_token.clone()),
+            ];
+            if let Some((name, value)) = &checksum_header {
+                header_pairs.push((name.clone(), value.clone()));
+            }
+            header_pairs.extend(sse_c_headers.clone());
+            let (canonical_headers, signed_headers) = Self::canonical_headers_and_signed(header_pairs);
+
+            let canonical_request = format!(
+                "{}\n{}\n{}\n{}\n{}\n{}",
+                method, canonical_uri, query, canonical_headers, signed_headers, content_sha256
+            );
+            let credential_scope = format!("{}/{}/s3/aws4_request", datestamp, self.region);
+            let string_to_sign = format!(
+                "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+                amz_date,
+                credential_scope,
+                hex::encode(Sha256::digest(canonical_request.as_bytes()))
+            );
+
+            let signature = self.get_signature(datestamp, &string_to_sign);
+            let auth_header = format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                self.access_key, credential_scope, signed_headers, signature
+            );
+
+            // Construct HTTP request
+            let opts = RequestInit::new();
+            opts.set_method(method);
+            opts.set_mode(RequestMode::Cors);
+            // Use copied Rust memory data
+            let uint8_data = Uint8Array::from(&chunk_data[..]);
+            opts.set_body(&uint8_data);
+
+            // Defensive check: Set AbortSignal if provided for cancellation support
+            if !signal.is_null() && !signal.is_undefined() {
+                opts.set_signal(Some(signal.unchecked_ref()));
+            }
+
+            let url = format!("{}{}?{}", self.endpoint.trim_end_matches('/'), canonical_uri, query);
+            let request = Request::new_with_str_and_init(&url, &opts)?;
+
+            let headers = request.headers();
+            headers.set("x-amz-date", &amz_date)?;
+            headers.set("x-amz-security-token", &self.session_token)?;
+            headers.set("x-amz-content-sha256", &content_sha256)?;
+            if let Some((name, value)) = &checksum_header {
+                headers.set(name, value)?;
+            }
+            for (name, value) in &sse_c_headers {
+                headers.set(name, value)?;
+            }
+            headers.set("Authorization", &auth_header)?;
+
+            Ok(request)
+        };
+
+        // Send request, retrying transient failures, and handle cancellation
+        let result = self
+            .execute_with_retry(build_request, signal, retry_config.part_timeout_ms, &retry_config)
+            .await;
+
+        let resp = match result {
+            Ok(resp) => resp,
+            Err(e) => {
+                // Auto-abort is scoped to exhausted retries, not to the
+                // caller cancelling this one part - don't tear down the
+                // whole upload out from under a pause/cancel-one-part flow.
+                if retry_config.on_error == OnError::AbortUpload && e.as_string().as_deref() != Some("USER_CANCELED") {
+                    let _ = self.abort_multipart_upload(bucket, object_key, upload_id, None).await;
+                }
+                return Err(e);
+            }
+        };
+
+        if !resp.ok() {
+            let error_text = JsFuture::from(resp.text()?).await?.as_string().unwrap_or_default();
+            if retry_config.on_error == OnError::AbortUpload {
+                let _ = self.abort_multipart_upload(bucket, object_key, upload_id, None).await;
+            }
+            return Err(JsValue::from_str(&format!("MinIO upload failed with status: {}, detail: {}", resp.status(), error_text)));
+        }
+
+        // Extract ETag from response headers (required for completion)
+        let etag = resp.headers().get("ETag")?.ok_or("No ETag")?;
+        Ok(etag.replace("\"", ""))
+    }
+
+    /// 执行分片上传（UploadPart 操作），使用 STREAMING-AWS4-HMAC-SHA256-PAYLOAD 分块签名
+    /// 与 `upload_part` 的区别：不对整个分片做一次性 SHA256，而是按 64KB 子块增量签名，
+    /// 避免大分片在签名前被整体哈希一遍（省去一次完整的同步 CPU 扫描）。
+    ///
+    /// `checksum_algorithm`/`checksum_value`: an optional additive checksum -
+    /// algorithm one of "CRC32", "CRC32C", "SHA1", "SHA256", and `checksum_value`
+    /// its base64 digest (from `IncrementalHasher::finalize_checksum`). Sent as
+    /// the matching `x-amz-checksum-<algorithm>` header (added to SignedHeaders).
+    /// The server verifies and echoes it back; pair it with `complete_multipart_upload`'s
+    /// `checksum_algorithm`/`part_checksums` so the completed object gets a
+    /// `<Checksum*>` element too - a more reliable end-to-end integrity check
+    /// than the ETag (which, in the multipart case, isn't a whole-object MD5).
+    ///
+    /// `encryption`: when the session was started with `EncryptionConfig::set_sse_c`,
+    /// the same config must be passed here too - S3 needs the customer key
+    /// replayed on every part to decrypt-then-re-encrypt it. SSE-S3/SSE-KMS
+    /// headers aren't needed here (set once at `initiate_multipart_upload`), so
+    /// passing an SSE-S3/SSE-KMS-only `EncryptionConfig` is a no-op.
+    ///
+    /// `retry_config`: retries a transient failure (network error, timeout,
+    /// 429, 5xx) the same way `upload_part` does - defaults to a single
+    /// attempt, and can auto-abort the whole upload once attempts are
+    /// exhausted via `RetryConfig::on_error`.
+    pub async fn upload_part_streaming(
+        &self,
+        bucket: String,
+        object_key: String,
+        upload_id: String,
+        part_number: u32,
+        chunk: Uint8Array,
+        signal: &JsValue,
+        checksum_algorithm: Option<String>,
+        checksum_value: Option<String>,
+        encryption: Option<EncryptionConfig>,
+        retry_config: Option<RetryConfig>,
+    ) -> Result<String, JsValue> {
+        // CRITICAL: Immediately copy JS data to Rust memory to avoid accessing
+        // invalidated JS pointers after async await points
+        let chunk_data = chunk.to_vec();
+        let decoded_content_length = chunk_data.len();
+        let retry_config = retry_config.unwrap_or_default();
+
+        // (header-name, value) for the optional additive checksum, computed
+        // once since both fields must be present together.
+        let checksum_header = match (&checksum_algorithm, &checksum_value) {
+            (Some(algorithm), Some(value)) => Some((checksum_header_name(algorithm), value.clone())),
+            _ => None,
+        };
+        let sse_c_headers = encryption.as_ref().map(|e| e.sse_c_header_pairs()).unwrap_or_default();
+
+        let method = "PUT";
+
+        let encoded_upload_id = encode_uri_component(&upload_id)
+            .as_string()
+            .unwrap_or_else(|| upload_id.clone());
+        let query = format!("partNumber={}&uploadId={}", part_number, encoded_upload_id);
+
+        let host = self.endpoint.replace("http://", "").replace("https://", "");
+
+        let clean_object_key = object_key.trim_start_matches('/');
+        let canonical_uri = uri_encode(&format!("/{}/{}", bucket, clean_object_key), false);
+
+        // Re-signs and re-frames the chunked body on every attempt, since both
+        // the seed signature and the per-chunk chain are bound to `x-amz-date`.
+        let build_request = || -> Result<Request, JsValue> {
+            let amz_date = self.get_amz_date();
+            let datestamp = &amz_date[..8];
+
+            // Seed signature: computed exactly like the normal Authorization signature,
+            // but using the STREAMING payload-hash literal in place of a real digest.
+            // `content-encoding` and `x-amz-decoded-content-length` describe the
+            // chunked framing itself and must be signed for the same reason as the
+            // checksum header: every x-amz-* (and framing) header actually sent on
+            // the wire has to appear in SignedHeaders or S3 rejects the request.
+            // The checksum header is optional, so - like `upload_part` - the
+            // signed-header set is built dynamically and sorted by name.
+            let mut header_pairs: Vec<(String, String)> = vec![
+                ("content-encoding".to_string(), "aws-chunked".to_string()),
+                ("host".to_string(), host.clone()),
+                ("x-amz-content-sha256".to_string(), STREAMING_PAYLOAD_HASH.to_string()),
+                ("x-amz-date".to_string(), amz_date.clone()),
+                ("x-amz-decoded-content-length".to_string(), decoded_content_length.to_string()),
+                ("x-amz-security-token".to_string(), self.session_token.clone()),
+            ];
+            if let Some((name, value)) = &checksum_header {
+                header_pairs.push((name.clone(), value.clone()));
+            }
+            header_pairs.extend(sse_c_headers.clone());
+            let (canonical_headers, signed_headers) = Self::canonical_headers_and_signed(header_pairs);
+
+            let canonical_request = format!(
+                "{}\n{}\n{}\n{}\n{}\n{}",
+                method, canonical_uri, query, canonical_headers, signed_headers, STREAMING_PAYLOAD_HASH
+            );
+            let credential_scope = format!("{}/{}/s3/aws4_request", datestamp, self.region);
+            let string_to_sign = format!(
+                "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+                amz_date,
+                credential_scope,
+                hex::encode(Sha256::digest(canonical_request.as_bytes()))
+            );
+
+            let seed_signature = self.get_signature(datestamp, &string_to_sign);
+            let auth_header = format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                self.access_key, credential_scope, signed_headers, seed_signature
+            );
+
+            // Frame the body as a chain of signed chunks, seeded with the seed signature.
+            let body = self.build_streaming_chunks(&chunk_data, datestamp, &amz_date, &credential_scope, &seed_signature);
+
+            let opts = RequestInit::new();
+            opts.set_method(method);
+            opts.set_mode(RequestMode::Cors);
+            let uint8_body = Uint8Array::from(&body[..]);
+            opts.set_body(&uint8_body);
+
+            if !signal.is_null() && !signal.is_undefined() {
+                opts.set_signal(Some(signal.unchecked_ref()));
+            }
+
+            let url = format!("{}{}?{}", self.endpoint.trim_end_matches('/'), canonical_uri, query);
+            let request = Request::new_with_str_and_init(&url, &opts)?;
+
+            let headers = request.headers();
+            headers.set("x-amz-date", &amz_date)?;
+            headers.set("x-amz-security-token", &self.session_token)?;
+            headers.set("x-amz-content-sha256", STREAMING_PAYLOAD_HASH)?;
+            headers.set("Content-Encoding", "aws-chunked")?;
+            headers.set("x-amz-decoded-content-length", &decoded_content_length.to_string())?;
+            if let Some((name, value)) = &checksum_header {
+                headers.set(name, value)?;
+            }
+            for (name, value) in &sse_c_headers {
+                headers.set(name, value)?;
+            }
+            headers.set("Authorization", &auth_header)?;
+
+            Ok(request)
+        };
+
+        let result = self
+            .execute_with_retry(build_request, signal, retry_config.part_timeout_ms, &retry_config)
+            .await;
+
+        let resp = match result {
+            Ok(resp) => resp,
+            Err(e) => {
+                // Auto-abort is scoped to exhausted retries, not to the
+                // caller cancelling this one part - don't tear down the
+                // whole upload out from under a pause/cancel-one-part flow.
+                if retry_config.on_error == OnError::AbortUpload && e.as_string().as_deref() != Some("USER_CANCELED") {
+                    let _ = self.abort_multipart_upload(bucket, object_key, upload_id, None).await;
+                }
+                return Err(e);
+            }
+        };
+
+        if !resp.ok() {
+            let error_text = JsFuture::from(resp.text()?).await?.as_string().unwrap_or_default();
+            if retry_config.on_error == OnError::AbortUpload {
+                let _ = self.abort_multipart_upload(bucket, object_key, upload_id, None).await;
+            }
+            return Err(JsValue::from_str(&format!("MinIO upload failed with status: {}, detail: {}", resp.status(), error_text)));
+        }
+
+        let etag = resp.headers().get("ETag")?.ok_or("No ETag")?;
+        Ok(etag.replace("\"", ""))
+    }
+
+    // ========================================================================
+    // Internal Helper: Frame a request body as STREAMING-AWS4-HMAC-SHA256-PAYLOAD
+    // chunks, chaining each chunk-signature from the previous one (starting from
+    // the seed signature), and terminating with a zero-length chunk.
+    // ========================================================================
+    fn build_streaming_chunks(
+        &self,
+        chunk_data: &[u8],
+        datestamp: &str,
+        amz_date: &str,
+        credential_scope: &str,
+        seed_signature: &str,
+    ) -> Vec<u8> {
+        let k_signing = self.derive_signing_key(datestamp);
+        let mut body = Vec::with_capacity(chunk_data.len() + chunk_data.len() / STREAMING_CHUNK_SIZE.max(1) * 96 + 128);
+        let mut previous_signature = seed_signature.to_string();
+
+        let sign_chunk = |data: &[u8], prev: &str| -> String {
+            let string_to_sign = format!(
+                "AWS4-HMAC-SHA256-PAYLOAD\n{}\n{}\n{}\n{}\n{}",
+                amz_date, credential_scope, prev, EMPTY_SHA256_HEX, hex::encode(Sha256::digest(data))
+            );
+            hex::encode(self.hmac_sha256(&k_signing, string_to_sign.as_bytes()))
+        };
+
+        for sub_chunk in chunk_data.chunks(STREAMING_CHUNK_SIZE) {
+            let chunk_sig = sign_chunk(sub_chunk, &previous_signature);
+            body.extend_from_slice(format!("{:x};chunk-signature={}\r\n", sub_chunk.len(), chunk_sig).as_bytes());
+            body.extend_from_slice(sub_chunk);
+            body.extend_from_slice(b"\r\n");
+            previous_signature = chunk_sig;
+        }
+
+        // Final zero-length chunk closes the chunk stream.
+        let final_sig = sign_chunk(&[], &previous_signature);
+        body.extend_from_slice(format!("0;chunk-signature={}\r\n\r\n", final_sig).as_bytes());
+
+        body
+    }
+
+    // ========================================================================
+    // Presigned Part-Upload URL (query-string SigV4)
+    // ========================================================================
+    // Returns a fully query-signed PUT URL for a single part, instead of
+    // performing the fetch itself. This lets the caller dispatch the actual
+    // upload through a plain XMLHttpRequest (for progress events) or a pool
+    // of parallel workers, since no Authorization header needs to be set.
+    //
+    // Uses UNSIGNED-PAYLOAD as the payload hash and signs only the `host`
+    // header; the rest of the auth material travels as query parameters,
+    // sorted alphabetically before signing as SigV4 requires.
+    // ========================================================================
+    pub fn generate_presigned_part_url(
+        &self,
+        bucket: String,
+        object_key: String,
+        upload_id: String,
+        part_number: u32,
+        expires_secs: u32,
+    ) -> Result<String, JsValue> {
+        let encoded_upload_id = encode_uri_component(&upload_id).as_string().unwrap_or_else(|| upload_id.clone());
+        self.presign_query_url(
+            "PUT",
+            &bucket,
+            &object_key,
+            vec![
+                ("partNumber".to_string(), part_number.to_string()),
+                ("uploadId".to_string(), encoded_upload_id),
+            ],
+            expires_secs,
+        )
+    }
+
+    // ========================================================================
+    // Presigned GET URL (query-string SigV4)
+    // ========================================================================
+    // Returns a time-limited download URL signed entirely through query
+    // parameters, so a browser can hand it to a plain `<a>`/`fetch` without
+    // setting auth headers - useful for sharing download links.
+    // ========================================================================
+    pub fn presign_get(&self, bucket: String, object_key: String, expires_secs: u32) -> Result<String, JsValue> {
+        self.presign_query_url("GET", &bucket, &object_key, vec![], expires_secs)
+    }
+
+    // Alias kept for API symmetry with `presign_get`: identical to
+    // `generate_presigned_part_url`, just named to match the `presign_*` family.
+    pub fn presign_put_part(
+        &self,
+        bucket: String,
+        object_key: String,
+        upload_id: String,
+        part_number: u32,
+        expires_secs: u32,
+    ) -> Result<String, JsValue> {
+        self.generate_presigned_part_url(bucket, object_key, upload_id, part_number, expires_secs)
+    }
+
+    // ========================================================================
+    // Internal Helper: Query-String SigV4 Presigning
+    // ========================================================================
+    // Shared by every `presign_*`/`generate_presigned_part_url` method. Moves
+    // the credential into `X-Amz-Credential`, adds `X-Amz-Date`,
+    // `X-Amz-Expires`, `X-Amz-SignedHeaders=host` and `X-Amz-Security-Token`
+    // as *query* parameters (sorted alphabetically, as SigV4 requires), uses
+    // `UNSIGNED-PAYLOAD` as the payload hash, and signs only the `host`
+    // header - nothing else needs to travel on the wire.
+    // ========================================================================
+    fn presign_query_url(
+        &self,
+        method: &str,
+        bucket: &str,
+        object_key: &str,
+        extra_query_pairs: Vec<(String, String)>,
+        expires_secs: u32,
+    ) -> Result<String, JsValue> {
         let host = self.endpoint.replace("http://", "").replace("https://", "");
         let amz_date = self.get_amz_date();
         let datestamp = &amz_date[..8];
+        let credential_scope = format!("{}/{}/s3/aws4_request", datestamp, self.region);
+        let credential = format!("{}/{}", self.access_key, credential_scope);
 
-        // Calculate SHA256 hash of the payload
-        let content_sha256 = hex::encode(Sha256::digest(&chunk_data));
-
-        // Construct canonical URI - must start with /
-        // Handle object_key that may already have leading slash to prevent //
         let clean_object_key = object_key.trim_start_matches('/');
-        let canonical_uri = format!("/{}/{}", bucket, clean_object_key);
-
-        // Construct canonical headers (order matters for signature)
-        let canonical_headers = format!(
-            "host:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\nx-amz-security-token:{}\n",
-            host, content_sha256, amz_date, self.session_token
-        );
-        let signed_headers = "host;x-amz-content-sha256;x-amz-date;x-amz-security-token";
+        let canonical_uri = uri_encode(&format!("/{}/{}", bucket, clean_object_key), false);
+
+        let encoded_credential = uri_encode(&credential, true);
+        let encoded_token = uri_encode(&self.session_token, true);
+
+        // Query parameters must be sorted alphabetically by name before signing.
+        let mut query_pairs = vec![
+            ("X-Amz-Algorithm".to_string(), "AWS4-HMAC-SHA256".to_string()),
+            ("X-Amz-Credential".to_string(), encoded_credential),
+            ("X-Amz-Date".to_string(), amz_date.clone()),
+            ("X-Amz-Expires".to_string(), expires_secs.to_string()),
+            ("X-Amz-Security-Token".to_string(), encoded_token),
+            ("X-Amz-SignedHeaders".to_string(), "host".to_string()),
+        ];
+        query_pairs.extend(extra_query_pairs);
+        query_pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        let canonical_querystring = query_pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let canonical_headers = format!("host:{}\n", host);
+        let signed_headers = "host";
 
         let canonical_request = format!(
             "{}\n{}\n{}\n{}\n{}\n{}",
-            method, canonical_uri, query, canonical_headers, signed_headers, content_sha256
+            method, canonical_uri, canonical_querystring, canonical_headers, signed_headers, UNSIGNED_PAYLOAD_HASH
         );
-        let credential_scope = format!("{}/{}/s3/aws4_request", datestamp, self.region);
         let string_to_sign = format!(
             "AWS4-HMAC-SHA256\n{}\n{}\n{}",
-            amz_date,
-            credential_scope,
-            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+            amz_date, credential_scope, hex::encode(Sha256::digest(canonical_request.as_bytes()))
         );
-
         let signature = self.get_signature(datestamp, &string_to_sign);
-        let auth_header = format!(
-            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
-            self.access_key, credential_scope, signed_headers, signature
-        );
-
-        // Construct HTTP request
-        let opts = RequestInit::new();
-        opts.set_method(method);
-        opts.set_mode(RequestMode::Cors);
-        // Use copied Rust memory data
-        let uint8_data = Uint8Array::from(&chunk_data[..]);
-        opts.set_body(&uint8_data);
-
-        // Defensive check: Set AbortSignal if provided for cancellation support
-        if !signal.is_null() && !signal.is_undefined() {
-            opts.set_signal(Some(signal.unchecked_ref()));
-        }
-
-        let url = format!("{}/{}/{}?{}", self.endpoint.trim_end_matches('/'), bucket, clean_object_key, query);
-        let request = Request::new_with_str_and_init(&url, &opts)?;
-        
-        let headers = request.headers();
-        headers.set("x-amz-date", &amz_date)?;
-        headers.set("x-amz-security-token", &self.session_token)?;
-        headers.set("x-amz-content-sha256", &content_sha256)?;
-        headers.set("Authorization", &auth_header)?;
-
-        // Send request and handle cancellation
-        let resp = self.fetch_with_abort_handling(&request).await?;
-
-        if !resp.ok() {
-            let error_text = JsFuture::from(resp.text()?).await?.as_string().unwrap_or_default();
-            return Err(JsValue::from_str(&format!("MinIO upload failed with status: {}, detail: {}", resp.status(), error_text)));
-        }
 
-        // Extract ETag from response headers (required for completion)
-        let etag = resp.headers().get("ETag")?.ok_or("No ETag")?;
-        Ok(etag.replace("\"", ""))
+        Ok(format!(
+            "{}{}?{}&X-Amz-Signature={}",
+            self.endpoint.trim_end_matches('/'), canonical_uri, canonical_querystring, signature
+        ))
     }
 
     // ========================================================================
@@ -319,23 +1298,55 @@ impl Uploader {
     // - Key caching support (same-day requests can reuse derived keys)
     // - Scope isolation (different services/regions use different keys)
     // ========================================================================
-    fn get_signature(&self, datestamp: &str, string_to_sign: &str) -> String {
+    fn derive_signing_key(&self, datestamp: &str) -> Vec<u8> {
+        // Reuse the cached kSigning if it was derived for the same datestamp -
+        // same-day S3 V4 requests can share one key, which matters when dozens
+        // of part uploads are firing in parallel.
+        if let Some((cached_datestamp, cached_key)) = self.signing_key_cache.borrow().as_ref() {
+            if cached_datestamp == datestamp {
+                return cached_key.clone();
+            }
+        }
+
         // Step 1: HMAC the date using "AWS4" + SecretKey as initial key
         let k_date = self.hmac_sha256(format!("AWS4{}", self.secret_key).as_bytes(), datestamp.as_bytes());
-        
+
         // Step 2: HMAC the region using kDate
         let k_region = self.hmac_sha256(&k_date, self.region.as_bytes());
-        
+
         // Step 3: HMAC the service name "s3" using kRegion
         let k_service = self.hmac_sha256(&k_region, b"s3");
-        
+
         // Step 4: HMAC "aws4_request" using kService to get final signing key
         let k_signing = self.hmac_sha256(&k_service, b"aws4_request");
 
+        *self.signing_key_cache.borrow_mut() = Some((datestamp.to_string(), k_signing.clone()));
+        k_signing
+    }
+
+    fn get_signature(&self, datestamp: &str, string_to_sign: &str) -> String {
+        let k_signing = self.derive_signing_key(datestamp);
+
         // Step 5: HMAC the string-to-sign using signing key and convert to hex
         hex::encode(self.hmac_sha256(&k_signing, string_to_sign.as_bytes()))
     }
 
+    // ========================================================================
+    // Internal Helper: Canonical Headers From an Optional Header Set
+    // ========================================================================
+    // Builds the `host:value\n...` canonical-headers block and matching
+    // `;`-joined SignedHeaders list from a list of (name, value) pairs,
+    // sorting by header name as SigV4 requires. Used whenever the set of
+    // signed headers is variable (e.g. an optional checksum header) rather
+    // than the fixed four headers most requests sign.
+    // ========================================================================
+    fn canonical_headers_and_signed(mut pairs: Vec<(String, String)>) -> (String, String) {
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        let canonical_headers = pairs.iter().map(|(k, v)| format!("{}:{}\n", k, v)).collect::<String>();
+        let signed_headers = pairs.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>().join(";");
+        (canonical_headers, signed_headers)
+    }
+
     // ========================================================================
     // HMAC-SHA256 Helper Function
     // ========================================================================
@@ -386,70 +1397,128 @@ impl Uploader {
     // - Incomplete uploads may incur storage costs
     // - Consider implementing automatic cleanup for abandoned uploads
     // ========================================================================
+    ///
+    /// `unsigned_payload`: kept in sync with the flag passed to `upload_part` for
+    /// the same session. The initiate request has no body either way, so this
+    /// only picks which zero-cost payload-hash literal is used for signing.
+    ///
+    /// `checksum_algorithm`: optional additive-checksum algorithm name (e.g.
+    /// `"CRC32C"`) sent as `x-amz-checksum-algorithm`, putting the session into
+    /// checksum mode so parts uploaded with `upload_part`'s `checksum_algorithm`/
+    /// `checksum_value` are verified server-side and echoed back.
+    ///
+    /// `put_opts`: optional Content-Type/Content-Encoding/Cache-Control, user
+    /// metadata and an object tag set applied to the finished object - see
+    /// `PutMultipartOpts`.
+    ///
+    /// `encryption`: optional server-side-encryption mode (SSE-S3, SSE-KMS or
+    /// SSE-C) applied to the finished object - see `EncryptionConfig`. For
+    /// SSE-C, the same config must also be passed to every `upload_part`/
+    /// `upload_part_streaming` call in this session.
     pub async fn initiate_multipart_upload(
         &self,
         bucket: String,
         object_key: String,
+        unsigned_payload: bool,
+        checksum_algorithm: Option<String>,
+        put_opts: Option<PutMultipartOpts>,
+        encryption: Option<EncryptionConfig>,
+        retry_config: Option<RetryConfig>,
     ) -> Result<String, JsValue> {
+        let retry_config = retry_config.unwrap_or_default();
         let method = "POST"; // HTTP method: POST for initiating multipart upload
-        
+
         // Normalize query string: for key-only parameters, must append '='
         // URL uses ?uploads, signature uses uploads=
         let canonical_querystring = "uploads=";
         let query_for_url = "uploads";
-        
+
         let host = self.endpoint.replace("http://", "").replace("https://", "");
-        let amz_date = self.get_amz_date();
-        let datestamp = &amz_date[..8];
 
-        // Empty payload for initialization, SHA256 is a fixed constant
-        let content_sha256 = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        // Empty payload either way: use the literal matching the session's mode.
+        let content_sha256 = if unsigned_payload { UNSIGNED_PAYLOAD_HASH } else { EMPTY_SHA256_HEX };
 
         // Ensure proper URI encoding (standard practice even for clean filenames)
-        let canonical_uri = format!("/{}/{}", bucket, object_key);
-
-        // Construct canonical request
-        let canonical_headers = format!(
-            "host:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\nx-amz-security-token:{}\n",
-            host, content_sha256, amz_date, self.session_token
-        );
-        let signed_headers = "host;x-amz-content-sha256;x-amz-date;x-amz-security-token";
-
-        let canonical_request = format!(
-            "{}\n{}\n{}\n{}\n{}\n{}",
-            method, canonical_uri, canonical_querystring, canonical_headers, signed_headers, content_sha256
-        );
-
-        let credential_scope = format!("{}/{}/s3/aws4_request", datestamp, self.region);
-        let string_to_sign = format!(
-            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
-            amz_date,
-            credential_scope,
-            hex::encode(Sha256::digest(canonical_request.as_bytes()))
-        );
-
-        let signature = self.get_signature(datestamp, &string_to_sign);
-        let auth_header = format!(
-            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
-            self.access_key, credential_scope, signed_headers, signature
-        );
-
-        // Construct and send HTTP request
-        let opts = RequestInit::new();
-        opts.set_method(method);
-        opts.set_mode(RequestMode::Cors);
+        let clean_object_key = object_key.trim_start_matches('/');
+        let canonical_uri = uri_encode(&format!("/{}/{}", bucket, clean_object_key), false);
+
+        let build_request = || -> Result<Request, JsValue> {
+            let amz_date = self.get_amz_date();
+            let datestamp = &amz_date[..8];
+
+            // Construct canonical request. The checksum-algorithm, PutMultipartOpts
+            // and EncryptionConfig headers are all optional, so the signed-header
+            // set is built dynamically and sorted by name.
+            let mut header_pairs: Vec<(String, String)> = vec![
+                ("host".to_string(), host.clone()),
+                ("x-amz-content-sha256".to_string(), content_sha256.to_string()),
+                ("x-amz-date".to_string(), amz_date.clone()),
+                ("x-amz-security-token".to_string(), self.session_token.clone()),
+            ];
+            if let Some(algorithm) = &checksum_algorithm {
+                header_pairs.push(("x-amz-checksum-algorithm".to_string(), algorithm.clone()));
+            }
+            if let Some(put_opts) = &put_opts {
+                header_pairs.extend(put_opts.header_pairs());
+            }
+            if let Some(encryption) = &encryption {
+                header_pairs.extend(encryption.header_pairs());
+            }
+            let (canonical_headers, signed_headers) = Self::canonical_headers_and_signed(header_pairs);
+
+            let canonical_request = format!(
+                "{}\n{}\n{}\n{}\n{}\n{}",
+                method, canonical_uri, canonical_querystring, canonical_headers, signed_headers, content_sha256
+            );
+
+            let credential_scope = format!("{}/{}/s3/aws4_request", datestamp, self.region);
+            let string_to_sign = format!(
+                "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+                amz_date,
+                credential_scope,
+                hex::encode(Sha256::digest(canonical_request.as_bytes()))
+            );
+
+            let signature = self.get_signature(datestamp, &string_to_sign);
+            let auth_header = format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                self.access_key, credential_scope, signed_headers, signature
+            );
+
+            // Construct and send HTTP request
+            let opts = RequestInit::new();
+            opts.set_method(method);
+            opts.set_mode(RequestMode::Cors);
+
+            // URL uses original ?uploads format
+            let url = format!("{}{}?{}", self.endpoint.trim_end_matches('/'), canonical_uri, query_for_url);
+            let request = Request::new_with_str_and_init(&url, &opts)?;
+
+            let headers = request.headers();
+            headers.set("x-amz-date", &amz_date)?;
+            headers.set("x-amz-security-token", &self.session_token)?;
+            headers.set("x-amz-content-sha256", content_sha256)?;
+            if let Some(algorithm) = &checksum_algorithm {
+                headers.set("x-amz-checksum-algorithm", algorithm)?;
+            }
+            if let Some(put_opts) = &put_opts {
+                for (name, value) in put_opts.header_pairs() {
+                    headers.set(&name, &value)?;
+                }
+            }
+            if let Some(encryption) = &encryption {
+                for (name, value) in encryption.header_pairs() {
+                    headers.set(&name, &value)?;
+                }
+            }
+            headers.set("Authorization", &auth_header)?;
 
-        // URL uses original ?uploads format
-        let url = format!("{}/{}/{}?{}", self.endpoint.trim_end_matches('/'), bucket, object_key, query_for_url);
-        let request = Request::new_with_str_and_init(&url, &opts)?;
-        
-        let headers = request.headers();
-        headers.set("x-amz-date", &amz_date)?;
-        headers.set("x-amz-security-token", &self.session_token)?;
-        headers.set("x-amz-content-sha256", content_sha256)?;
-        headers.set("Authorization", &auth_header)?;
+            Ok(request)
+        };
 
-        let resp = self.fetch_with_abort_handling(&request).await?;
+        let resp = self
+            .execute_with_retry(build_request, &JsValue::NULL, retry_config.create_timeout_ms, &retry_config)
+            .await?;
 
         if !resp.ok() {
             let error_text = JsFuture::from(resp.text()?).await?.as_string().unwrap_or_default();
@@ -480,7 +1549,21 @@ impl Uploader {
     // - upload_id: Upload session ID (returned by initiate_multipart_upload)
     // - parts_data: All part information in format "partNumber:etag,partNumber:etag,..."
     //               Example: "1:abc123,2:def456,3:ghi789"
+    // - checksum_algorithm: optional additive-checksum algorithm name (e.g.
+    //               "SHA256"), matching whatever was passed to `upload_part`/
+    //               `upload_part_streaming`. Selects which <Checksum*> element
+    //               `part_checksums` values are written under.
+    // - part_checksums: optional per-part checksums in the same
+    //               "partNumber:checksum,..." format: the base64 values passed
+    //               as `checksum_value` to each `upload_part` call
+    //               (`upload_part` returns only the ETag, not the checksum
+    //               header S3 echoes back). When present (together with
+    //               `checksum_algorithm`), each matching <Part> gets a
+    //               <Checksum*> element so S3 verifies the completed object.
     // - signal: AbortSignal for cancellation support
+    // - retry_config: retries a transient failure (network error, timeout,
+    //               429, 5xx) finalizing the upload, same as the other
+    //               mutating calls - defaults to a single attempt
     //
     // Returns:
     // - Ok(String): Final file access URL
@@ -499,18 +1582,33 @@ impl Uploader {
         object_key: String,
         upload_id: String,
         parts_data: String,
+        checksum_algorithm: Option<String>,
+        part_checksums: Option<String>,
         signal: &JsValue,
+        retry_config: Option<RetryConfig>,
     ) -> Result<String, JsValue> {
+        let retry_config = retry_config.unwrap_or_default();
         let method = "POST"; // HTTP method: POST for completing multipart upload
         let host = self.endpoint.replace("https://", "").replace("http://", "");
         let query = format!("uploadId={}", upload_id);
-        let amz_date = self.get_amz_date();
-        let datestamp = &amz_date[..8];
+
+        // partNumber -> checksum, if the caller supplied per-part checksums
+        let checksums: std::collections::HashMap<&str, &str> = part_checksums
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.splitn(2, ':');
+                Some((parts.next()?.trim(), parts.next()?.trim()))
+            })
+            .filter(|(n, c)| !n.is_empty() && !c.is_empty())
+            .collect();
+        let checksum_tag = checksum_algorithm.as_deref().map(checksum_xml_tag);
 
         // Construct S3-required merge XML request body
         // XML format:
         // <CompleteMultipartUpload>
-        //   <Part><PartNumber>1</PartNumber><ETag>"abc123"</ETag></Part>
+        //   <Part><PartNumber>1</PartNumber><ETag>"abc123"</ETag><ChecksumSHA256>...</ChecksumSHA256></Part>
         //   <Part><PartNumber>2</PartNumber><ETag>"def456"</ETag></Part>
         //   ...
         // </CompleteMultipartUpload>
@@ -518,8 +1616,14 @@ impl Uploader {
         for item in parts_data.split(',') {
             let p: Vec<&str> = item.split(':').collect();
             if p.len() == 2 {
+                xml_body.push_str("<Part>");
+                xml_body.push_str(&format!("<PartNumber>{}</PartNumber>", p[0]));
                 // Note: ETag must be wrapped in double quotes
-                xml_body.push_str(&format!("<Part><PartNumber>{}</PartNumber><ETag>\"{}\"</ETag></Part>", p[0], p[1]));
+                xml_body.push_str(&format!("<ETag>\"{}\"</ETag>", p[1]));
+                if let (Some(tag), Some(checksum)) = (&checksum_tag, checksums.get(p[0].trim())) {
+                    xml_body.push_str(&format!("<{}>{}</{}>", tag, checksum, tag));
+                }
+                xml_body.push_str("</Part>");
             }
         }
         xml_body.push_str("</CompleteMultipartUpload>");
@@ -527,36 +1631,48 @@ impl Uploader {
         // Calculate SHA256 hash of XML request body
         let content_sha256 = hex::encode(Sha256::digest(xml_body.as_bytes()));
 
-        let canonical_uri = format!("/{}/{}", bucket, object_key);
-        
-        // Calculate S3 V4 signature
-        let auth_header = self.calculate_v4_auth(
-            method, &canonical_uri, &query, &amz_date, datestamp, &content_sha256, &host, "host;x-amz-content-sha256;x-amz-date;x-amz-security-token"
-        );
-
-        // Construct HTTP request
-        let opts: RequestInit = RequestInit::new();
-        // Defensive check: Set AbortSignal if provided for cancellation support
-        if !signal.is_null() && !signal.is_undefined() {
-            opts.set_signal(Some(signal.unchecked_ref()));
-        }
-        opts.set_method(method);
-        opts.set_mode(RequestMode::Cors);
-        opts.set_body(&JsValue::from_str(&xml_body));
-
-        let url = format!("{}/{}/{}?{}", self.endpoint, bucket, object_key, query);
-        let request = Request::new_with_str_and_init(&url, &opts)?;
-        
-        // Set request headers
-        let headers = request.headers();
-        headers.set("Content-Type", "application/xml")?;  // Must specify XML content type
-        headers.set("x-amz-date", &amz_date)?;
-        headers.set("x-amz-security-token", &self.session_token)?;
-        headers.set("x-amz-content-sha256", &content_sha256)?;
-        headers.set("Authorization", &auth_header)?;
+        let clean_object_key = object_key.trim_start_matches('/');
+        let canonical_uri = uri_encode(&format!("/{}/{}", bucket, clean_object_key), false);
+
+        // Re-signs and rebuilds the request on every attempt, since the
+        // signature is bound to a fresh `x-amz-date` each time.
+        let build_request = || -> Result<Request, JsValue> {
+            let amz_date = self.get_amz_date();
+            let datestamp = &amz_date[..8];
+
+            // Calculate S3 V4 signature
+            let auth_header = self.calculate_v4_auth(
+                method, &canonical_uri, &query, &amz_date, datestamp, &content_sha256, &host, "host;x-amz-content-sha256;x-amz-date;x-amz-security-token"
+            );
+
+            // Construct HTTP request
+            let opts: RequestInit = RequestInit::new();
+            // Defensive check: Set AbortSignal if provided for cancellation support
+            if !signal.is_null() && !signal.is_undefined() {
+                opts.set_signal(Some(signal.unchecked_ref()));
+            }
+            opts.set_method(method);
+            opts.set_mode(RequestMode::Cors);
+            opts.set_body(&JsValue::from_str(&xml_body));
+
+            let url = format!("{}{}?{}", self.endpoint.trim_end_matches('/'), canonical_uri, query);
+            let request = Request::new_with_str_and_init(&url, &opts)?;
+
+            // Set request headers
+            let headers = request.headers();
+            headers.set("Content-Type", "application/xml")?;  // Must specify XML content type
+            headers.set("x-amz-date", &amz_date)?;
+            headers.set("x-amz-security-token", &self.session_token)?;
+            headers.set("x-amz-content-sha256", &content_sha256)?;
+            headers.set("Authorization", &auth_header)?;
+
+            Ok(request)
+        };
 
-        // Send request and handle cancellation
-        let resp = self.fetch_with_abort_handling(&request).await?;
+        // Send request, retrying transient failures, and handle cancellation
+        let resp = self
+            .execute_with_retry(build_request, signal, retry_config.complete_timeout_ms, &retry_config)
+            .await?;
 
         // Check response status code
         if !resp.ok() {
@@ -564,15 +1680,14 @@ impl Uploader {
                 .await?
                 .as_string()
                 .unwrap_or_default();
-            return Err(JsValue::from_str(&format!(
-                "Complete multipart upload failed ({}): {}",
-                resp.status(),
-                error_text
-            )));
+            return Err(S3Error::parse(resp.status(), &error_text).into());
         }
 
-        // Return final file access URL
-        Ok(format!("{}/{}/{}", self.endpoint, bucket, object_key))
+        // Return final file access URL, built from the same canonical_uri
+        // used to sign/address the request above, so a leading-slash or
+        // percent-encoded object_key doesn't produce a URL that doesn't
+        // match the object S3 actually stored.
+        Ok(format!("{}{}", self.endpoint.trim_end_matches('/'), canonical_uri))
     }
 
     // ========================================================================
@@ -632,6 +1747,195 @@ impl Uploader {
                 self.access_key, credential_scope, signed_headers, signature)
     }
 
+    // ========================================================================
+    // Reusable Standalone V4 Signer
+    // ========================================================================
+    // Generalizes `calculate_v4_auth` to an arbitrary header set so callers
+    // can sign S3 operations this crate doesn't wrap directly (DELETE, HEAD,
+    // GetObject, ...) without duplicating the canonical-request derivation.
+    //
+    // Parameters:
+    // - method: HTTP method
+    // - uri: Canonical URI (already uri_encode'd, e.g. via `uri_encode`)
+    // - query: Canonical query string
+    // - headers: Newline-separated `name:value` pairs to sign (lowercase
+    //            names; must include `host` - `x-amz-date` is added
+    //            automatically using the timestamp generated for this call)
+    // - payload_sha256: Payload hash to sign (a real digest, or one of the
+    //            `UNSIGNED-PAYLOAD` / `STREAMING-AWS4-HMAC-SHA256-PAYLOAD`
+    //            literals)
+    //
+    // Returns:
+    // - `"<x-amz-date>\n<Authorization header value>"` - the caller must send
+    //   both the literal x-amz-date value (it's embedded in the signature)
+    //   and the Authorization header on the actual request.
+    // ========================================================================
+    pub fn sign_request(
+        &self,
+        method: String,
+        uri: String,
+        query: String,
+        headers: String,
+        payload_sha256: String,
+    ) -> String {
+        let amz_date = self.get_amz_date();
+        let datestamp = &amz_date[..8];
+
+        let mut header_pairs: Vec<(String, String)> = headers
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(2, ':');
+                Some((parts.next()?.trim().to_string(), parts.next()?.trim().to_string()))
+            })
+            .collect();
+        header_pairs.push(("x-amz-date".to_string(), amz_date.clone()));
+        let (canonical_headers, signed_headers) = Self::canonical_headers_and_signed(header_pairs);
+
+        let canonical_request = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}",
+            method, uri, query, canonical_headers, signed_headers, payload_sha256
+        );
+        let credential_scope = format!("{}/{}/s3/aws4_request", datestamp, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date, credential_scope, hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+        let signature = self.get_signature(datestamp, &string_to_sign);
+        let auth_header = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key, credential_scope, signed_headers, signature
+        );
+
+        format!("{}\n{}", amz_date, auth_header)
+    }
+
+    // ========================================================================
+    // List Parts (Resumable Upload Support)
+    // ========================================================================
+    // Issues signed `GET ?uploadId=...` requests and returns the already-
+    // uploaded parts as a "partNumber:etag,..." string - the same format
+    // `complete_multipart_upload` expects for `parts_data` - so the frontend
+    // can skip re-uploading parts that already landed after e.g. a page
+    // reload, instead of restarting the whole upload from scratch.
+    //
+    // S3 caps a single ListParts response at 1000 parts, so this pages
+    // through `IsTruncated`/`NextPartNumberMarker` until the full list is
+    // collected - otherwise an upload with more parts than that would
+    // silently report only its first 1000 on resume.
+    // ========================================================================
+    pub async fn list_parts(
+        &self,
+        bucket: String,
+        object_key: String,
+        upload_id: String,
+    ) -> Result<String, JsValue> {
+        // Normalize a leading slash the same way upload_part/presign_* do, so
+        // a caller passing "/key" signs/requests the same path those calls
+        // use instead of desyncing onto "/bucket//key".
+        let clean_object_key = object_key.trim_start_matches('/').to_string();
+
+        let mut pages = Vec::new();
+        let mut part_number_marker: Option<String> = None;
+        loop {
+            let (parts_csv, is_truncated, next_marker) = self
+                .list_parts_page(&bucket, &clean_object_key, &upload_id, part_number_marker.as_deref())
+                .await?;
+            if !parts_csv.is_empty() {
+                pages.push(parts_csv);
+            }
+            if !is_truncated {
+                break;
+            }
+            let Some(next_marker) = next_marker else { break };
+            part_number_marker = Some(next_marker);
+        }
+        Ok(pages.join(","))
+    }
+
+    // Issues one page of `GET ?uploadId=...[&part-number-marker=...]` and
+    // returns (this page's "partNumber:etag,..." pairs, IsTruncated, NextPartNumberMarker).
+    async fn list_parts_page(
+        &self,
+        bucket: &str,
+        object_key: &str,
+        upload_id: &str,
+        part_number_marker: Option<&str>,
+    ) -> Result<(String, bool, Option<String>), JsValue> {
+        let method = "GET";
+        let host = self.endpoint.replace("http://", "").replace("https://", "");
+        let encoded_upload_id = encode_uri_component(upload_id)
+            .as_string()
+            .unwrap_or_else(|| upload_id.to_string());
+        let query = match part_number_marker {
+            Some(marker) => format!("part-number-marker={}&uploadId={}", marker, encoded_upload_id),
+            None => format!("uploadId={}", encoded_upload_id),
+        };
+        let canonical_uri = uri_encode(&format!("/{}/{}", bucket, object_key), false);
+
+        let headers = format!(
+            "host:{}\nx-amz-content-sha256:{}\nx-amz-security-token:{}\n",
+            host, EMPTY_SHA256_HEX, self.session_token
+        );
+        let signed = self.sign_request(method.to_string(), canonical_uri.clone(), query.clone(), headers, EMPTY_SHA256_HEX.to_string());
+        let mut signed_parts = signed.splitn(2, '\n');
+        let amz_date = signed_parts.next().unwrap_or_default().to_string();
+        let auth_header = signed_parts.next().unwrap_or_default().to_string();
+
+        let opts = RequestInit::new();
+        opts.set_method(method);
+        opts.set_mode(RequestMode::Cors);
+
+        let url = format!("{}{}?{}", self.endpoint.trim_end_matches('/'), canonical_uri, query);
+        let request = Request::new_with_str_and_init(&url, &opts)?;
+
+        let req_headers = request.headers();
+        req_headers.set("x-amz-date", &amz_date)?;
+        req_headers.set("x-amz-security-token", &self.session_token)?;
+        req_headers.set("x-amz-content-sha256", EMPTY_SHA256_HEX)?;
+        req_headers.set("Authorization", &auth_header)?;
+
+        let resp = self.fetch_with_abort_handling(&request, 0).await?;
+
+        if !resp.ok() {
+            let error_text = JsFuture::from(resp.text()?).await?.as_string().unwrap_or_default();
+            return Err(JsValue::from_str(&format!("ListParts failed ({}): {}", resp.status(), error_text)));
+        }
+
+        let text = JsFuture::from(resp.text()?).await?.as_string().unwrap_or_default();
+        Ok(Self::parse_list_parts_xml(&text))
+    }
+
+    // Extracts "partNumber:etag,..." pairs from one ListParts XML page, plus
+    // the pagination cursor (`IsTruncated`/`NextPartNumberMarker`) S3 includes
+    // when a response doesn't cover every part.
+    fn parse_list_parts_xml(xml: &str) -> (String, bool, Option<String>) {
+        let mut results = Vec::new();
+        let mut rest = xml;
+        while let Some(start) = rest.find("<Part>") {
+            let after_start = &rest[start + "<Part>".len()..];
+            let Some(end) = after_start.find("</Part>") else { break };
+            let part_xml = &after_start[..end];
+            if let (Some(part_number), Some(etag)) = (
+                Self::extract_xml_tag(part_xml, "PartNumber"),
+                Self::extract_xml_tag(part_xml, "ETag"),
+            ) {
+                results.push(format!("{}:{}", part_number, etag.replace('"', "")));
+            }
+            rest = &after_start[end + "</Part>".len()..];
+        }
+        let is_truncated = Self::extract_xml_tag(xml, "IsTruncated").as_deref() == Some("true");
+        let next_marker = Self::extract_xml_tag(xml, "NextPartNumberMarker");
+        (results.join(","), is_truncated, next_marker)
+    }
+
+    fn extract_xml_tag(xml: &str, tag: &str) -> Option<String> {
+        let open = format!("<{}>", tag);
+        let close = format!("</{}>", tag);
+        let start = xml.find(&open)? + open.len();
+        let end = start + xml[start..].find(&close)?;
+        Some(xml[start..end].to_string())
+    }
+
     // ========================================================================
     // Internal Helper: Get Current UTC Time in ISO8601 Format
     // ========================================================================
@@ -670,8 +1974,11 @@ impl Uploader {
     // - Detects AbortError from AbortSignal and converts to "USER_CANCELED"
     // - Works in both Window and Worker contexts
     // - Allows caller to distinguish cancellation from failure
+    // - `timeout_ms` of 0 disables the soft timeout (waits on the fetch alone);
+    //   otherwise a timed-out attempt resolves to Err("REQUEST_TIMEOUT"), which
+    //   `execute_with_retry` treats as retryable
     // ========================================================================
-    async fn fetch_with_abort_handling(&self, request: &Request) -> Result<web_sys::Response, JsValue> {
+    async fn fetch_with_abort_handling(&self, request: &Request, timeout_ms: u32) -> Result<web_sys::Response, JsValue> {
         // Inner helper function: Handle fetch errors
         fn handle_fetch_error(e: JsValue) -> Result<JsValue, JsValue> {
             if let Some(dom_err) = e.dyn_ref::<web_sys::DomException>() {
@@ -683,22 +1990,161 @@ impl Uploader {
         }
 
         let global = js_sys::global();
-        
+
         // Try Window context first, fallback to Worker context
-        let resp_value = if let Some(window) = web_sys::window() {
-            JsFuture::from(window.fetch_with_request(request))
-                .await
-                .or_else(handle_fetch_error)?
+        let fetch_promise = if let Some(window) = web_sys::window() {
+            window.fetch_with_request(request)
         } else {
             let worker_global = global.unchecked_into::<WorkerGlobalScope>();
-            JsFuture::from(worker_global.fetch_with_request(request))
-                .await
-                .or_else(handle_fetch_error)?
+            worker_global.fetch_with_request(request)
         };
-        
+
+        let resp_value = Self::race_with_timeout(fetch_promise, timeout_ms)
+            .await
+            .or_else(handle_fetch_error)?;
+
         resp_value.dyn_into()
     }
 
+    // Races a fetch promise against a timer, without aborting the underlying
+    // request (no AbortController to compose with the caller's own signal) -
+    // just stops waiting so `execute_with_retry` can try again. `timeout_ms`
+    // of 0 means "no timeout".
+    async fn race_with_timeout(fetch_promise: js_sys::Promise, timeout_ms: u32) -> Result<JsValue, JsValue> {
+        if timeout_ms == 0 {
+            return JsFuture::from(fetch_promise).await;
+        }
+
+        const TIMEOUT_SENTINEL: &str = "__uploader_wasm_timeout__";
+        let timeout_promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            let on_timeout = Closure::once_into_js(move || {
+                let _ = resolve.call1(&JsValue::NULL, &JsValue::from_str(TIMEOUT_SENTINEL));
+            });
+            let callback = on_timeout.unchecked_ref();
+            if let Some(window) = web_sys::window() {
+                let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(callback, timeout_ms as i32);
+            } else {
+                let worker_global = js_sys::global().unchecked_into::<WorkerGlobalScope>();
+                let _ = worker_global.set_timeout_with_callback_and_timeout_and_arguments_0(callback, timeout_ms as i32);
+            }
+        });
+
+        let race = js_sys::Promise::race(&js_sys::Array::of2(&fetch_promise, &timeout_promise));
+        let result = JsFuture::from(race).await?;
+        if result.as_string().as_deref() == Some(TIMEOUT_SENTINEL) {
+            return Err(JsValue::from_str("REQUEST_TIMEOUT"));
+        }
+        Ok(result)
+    }
+
+    // Sleeps for `ms` milliseconds, resolving early with Err("USER_CANCELED")
+    // if the caller's AbortSignal fires first.
+    async fn sleep_with_abort(&self, ms: u32, signal: &JsValue) -> Result<(), JsValue> {
+        if ms == 0 {
+            return Ok(());
+        }
+
+        let abort_signal = if !signal.is_null() && !signal.is_undefined() {
+            signal.dyn_ref::<web_sys::AbortSignal>().cloned()
+        } else {
+            None
+        };
+        if let Some(sig) = &abort_signal {
+            if sig.aborted() {
+                return Err(JsValue::from_str("USER_CANCELED"));
+            }
+        }
+
+        let promise = js_sys::Promise::new(&mut |resolve, reject| {
+            let on_timeout = Closure::once_into_js(move || {
+                let _ = resolve.call0(&JsValue::NULL);
+            });
+            let callback = on_timeout.unchecked_ref();
+            if let Some(window) = web_sys::window() {
+                let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(callback, ms as i32);
+            } else {
+                let worker_global = js_sys::global().unchecked_into::<WorkerGlobalScope>();
+                let _ = worker_global.set_timeout_with_callback_and_timeout_and_arguments_0(callback, ms as i32);
+            }
+
+            if let Some(sig) = &abort_signal {
+                let reject_on_abort = reject.clone();
+                let on_abort = Closure::once_into_js(move || {
+                    let _ = reject_on_abort.call1(&JsValue::NULL, &JsValue::from_str("USER_CANCELED"));
+                });
+                let _ = sig.add_event_listener_with_callback("abort", on_abort.unchecked_ref());
+            }
+        });
+
+        JsFuture::from(promise).await.map(|_| ())
+    }
+
+    // Runs `build_request` (re-signing from scratch each attempt, since the
+    // signature is bound to `x-amz-date`) and retries on network errors,
+    // soft timeouts, HTTP 429, HTTP 5xx, and S3's own `RequestTimeout`/
+    // `SlowDown` error codes (which S3 sometimes reports as a 400, not a
+    // 5xx), backing off exponentially with jitter between attempts and
+    // honoring `signal` while sleeping.
+    async fn execute_with_retry<F>(
+        &self,
+        mut build_request: F,
+        signal: &JsValue,
+        timeout_ms: u32,
+        retry_config: &RetryConfig,
+    ) -> Result<web_sys::Response, JsValue>
+    where
+        F: FnMut() -> Result<Request, JsValue>,
+    {
+        let mut attempt: u32 = 0;
+        loop {
+            attempt += 1;
+            let request = build_request()?;
+            match self.fetch_with_abort_handling(&request, timeout_ms).await {
+                Ok(resp) => {
+                    let status = resp.status();
+                    let mut retryable = status == 429 || status >= 500;
+                    // S3 reports a slow part upload as a 400 `RequestTimeout`,
+                    // not a 5xx, so the status-only check above misses it.
+                    // Peek at the body (via a clone, so the original response
+                    // is left untouched for the caller to read) and classify
+                    // it with `S3Error::parse` rather than guessing off the
+                    // status code alone. This is deliberately narrow: a 400
+                    // `BadDigest`/checksum-mismatch (chunk1-6's per-part
+                    // checksums) parses to a *different* `S3ErrorCode` and is
+                    // correctly left non-retryable - retrying it would just
+                    // re-send the same bytes against the same mismatch.
+                    if !retryable && status == 400 {
+                        if let Ok(clone) = resp.clone() {
+                            if let Ok(text) = JsFuture::from(clone.text()?).await {
+                                let body = text.as_string().unwrap_or_default();
+                                retryable = matches!(
+                                    S3Error::parse(status, &body).code(),
+                                    S3ErrorCode::RequestTimeout | S3ErrorCode::SlowDown
+                                );
+                            }
+                        }
+                    }
+                    if !retryable || attempt >= retry_config.max_attempts {
+                        return Ok(resp);
+                    }
+                }
+                Err(e) => {
+                    if e.as_string().as_deref() == Some("USER_CANCELED") || attempt >= retry_config.max_attempts {
+                        return Err(e);
+                    }
+                }
+            }
+
+            let shift = (attempt - 1).min(16);
+            let capped_backoff = retry_config
+                .base_backoff_ms
+                .saturating_mul(1u32 << shift)
+                .min(retry_config.max_backoff_ms);
+            let jittered = (js_sys::Math::random() * capped_backoff as f64) as u32;
+            self.sleep_with_abort(jittered, signal).await?;
+        }
+    }
+
     // ========================================================================
     // Abort Multipart Upload
     // ========================================================================
@@ -727,12 +2173,12 @@ impl Uploader {
         bucket: String,
         object_key: String,
         upload_id: String,
+        retry_config: Option<RetryConfig>,
     ) -> Result<(), JsValue> {
+        let retry_config = retry_config.unwrap_or_default();
         let method = "DELETE";
         let host = self.endpoint.replace("https://", "").replace("http://", "");
-        let amz_date = self.get_amz_date();
-        let datestamp = &amz_date[..8];
-        
+
         // Encode upload_id to handle special characters
         let encoded_upload_id = encode_uri_component(&upload_id)
             .as_string()
@@ -740,39 +2186,151 @@ impl Uploader {
         let query = format!("uploadId={}", encoded_upload_id);
 
         // DELETE requests typically have no body, SHA256 is empty hash constant
-        let content_sha256 = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
-        let canonical_uri = format!("/{}/{}", bucket, object_key);
-
-        let auth_header = self.calculate_v4_auth(
-            method,
-            &canonical_uri,
-            &query,
-            &amz_date,
-            datestamp,
-            content_sha256,
-            &host,
-            "host;x-amz-content-sha256;x-amz-date;x-amz-security-token"
-        );
-
-        let opts = RequestInit::new();
-        opts.set_method(method);
-        opts.set_mode(RequestMode::Cors);
-
-        let url = format!("{}/{}/{}?{}", self.endpoint.trim_end_matches('/'), bucket, object_key, query);
-        let request = Request::new_with_str_and_init(&url, &opts)?;
-        
-        let headers = request.headers();
-        headers.set("x-amz-date", &amz_date)?;
-        headers.set("x-amz-security-token", &self.session_token)?;
-        headers.set("x-amz-content-sha256", content_sha256)?;
-        headers.set("Authorization", &auth_header)?;
+        let content_sha256 = EMPTY_SHA256_HEX;
+        let clean_object_key = object_key.trim_start_matches('/');
+        let canonical_uri = uri_encode(&format!("/{}/{}", bucket, clean_object_key), false);
+
+        let build_request = || -> Result<Request, JsValue> {
+            let amz_date = self.get_amz_date();
+            let datestamp = &amz_date[..8];
+
+            let auth_header = self.calculate_v4_auth(
+                method,
+                &canonical_uri,
+                &query,
+                &amz_date,
+                datestamp,
+                content_sha256,
+                &host,
+                "host;x-amz-content-sha256;x-amz-date;x-amz-security-token"
+            );
+
+            let opts = RequestInit::new();
+            opts.set_method(method);
+            opts.set_mode(RequestMode::Cors);
+
+            let url = format!("{}{}?{}", self.endpoint.trim_end_matches('/'), canonical_uri, query);
+            let request = Request::new_with_str_and_init(&url, &opts)?;
+
+            let headers = request.headers();
+            headers.set("x-amz-date", &amz_date)?;
+            headers.set("x-amz-security-token", &self.session_token)?;
+            headers.set("x-amz-content-sha256", content_sha256)?;
+            headers.set("Authorization", &auth_header)?;
+
+            Ok(request)
+        };
 
-        let resp = self.fetch_with_abort_handling(&request).await?;
+        let resp = self
+            .execute_with_retry(build_request, &JsValue::NULL, retry_config.abort_timeout_ms, &retry_config)
+            .await?;
 
         if !resp.ok() {
-            return Err(JsValue::from_str("Abort multipart upload failed"));
+            let error_text = JsFuture::from(resp.text()?).await?.as_string().unwrap_or_default();
+            return Err(S3Error::parse(resp.status(), &error_text).into());
         }
 
         Ok(())
     }
+}
+
+#[cfg(test)]
+mod parse_list_parts_xml_tests {
+    use super::Uploader;
+
+    #[test]
+    fn extracts_part_number_etag_pairs_and_strips_etag_quotes() {
+        let xml = "<ListPartsResult><IsTruncated>false</IsTruncated>\
+            <Part><PartNumber>1</PartNumber><ETag>\"etag1\"</ETag></Part>\
+            <Part><PartNumber>2</PartNumber><ETag>\"etag2\"</ETag></Part>\
+            </ListPartsResult>";
+        let (parts, is_truncated, next_marker) = Uploader::parse_list_parts_xml(xml);
+        assert_eq!(parts, "1:etag1,2:etag2");
+        assert!(!is_truncated);
+        assert_eq!(next_marker, None);
+    }
+
+    #[test]
+    fn reports_truncation_and_next_marker_for_a_paged_response() {
+        let xml = "<ListPartsResult><IsTruncated>true</IsTruncated>\
+            <NextPartNumberMarker>1000</NextPartNumberMarker>\
+            <Part><PartNumber>1</PartNumber><ETag>\"etag1\"</ETag></Part>\
+            </ListPartsResult>";
+        let (parts, is_truncated, next_marker) = Uploader::parse_list_parts_xml(xml);
+        assert_eq!(parts, "1:etag1");
+        assert!(is_truncated);
+        assert_eq!(next_marker, Some("1000".to_string()));
+    }
+
+    #[test]
+    fn returns_empty_string_when_there_are_no_parts() {
+        let xml = "<ListPartsResult><IsTruncated>false</IsTruncated></ListPartsResult>";
+        let (parts, is_truncated, next_marker) = Uploader::parse_list_parts_xml(xml);
+        assert_eq!(parts, "");
+        assert!(!is_truncated);
+        assert_eq!(next_marker, None);
+    }
+}
+
+#[cfg(test)]
+mod extract_xml_tag_tests {
+    use super::Uploader;
+
+    #[test]
+    fn extracts_the_text_between_a_tags_open_and_close() {
+        assert_eq!(
+            Uploader::extract_xml_tag("<Code>NoSuchUpload</Code>", "Code"),
+            Some("NoSuchUpload".to_string())
+        );
+    }
+
+    #[test]
+    fn returns_none_when_the_tag_is_absent() {
+        assert_eq!(Uploader::extract_xml_tag("<Other>x</Other>", "Code"), None);
+    }
+}
+
+#[cfg(test)]
+mod build_streaming_chunks_tests {
+    use super::Uploader;
+
+    fn test_uploader() -> Uploader {
+        Uploader::new(
+            "AKIDEXAMPLE".to_string(),
+            "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY".to_string(),
+            String::new(),
+            "us-east-1".to_string(),
+            "https://s3.amazonaws.com".to_string(),
+        )
+    }
+
+    #[test]
+    fn frames_small_payload_as_single_chunk_with_hex_size_prefix() {
+        let uploader = test_uploader();
+        let data = b"hello world";
+        let body = uploader.build_streaming_chunks(data, "20260206", "20260206T000000Z", "scope", &"0".repeat(64));
+        let body_str = String::from_utf8(body).unwrap();
+        assert!(body_str.starts_with(&format!("{:x};chunk-signature=", data.len())));
+    }
+
+    #[test]
+    fn terminates_with_zero_length_chunk_trailer() {
+        let uploader = test_uploader();
+        let body = uploader.build_streaming_chunks(b"hello", "20260206", "20260206T000000Z", "scope", &"0".repeat(64));
+        let body_str = String::from_utf8(body).unwrap();
+        let trailer_start = body_str.rfind("0;chunk-signature=").expect("zero-length terminator chunk");
+        let trailer = &body_str[trailer_start..];
+        // "0;chunk-signature=" + 64 lowercase-hex chars + "\r\n\r\n"
+        assert_eq!(trailer.len(), "0;chunk-signature=".len() + 64 + 4);
+        assert!(trailer.ends_with("\r\n\r\n"));
+    }
+
+    #[test]
+    fn chains_each_chunk_signature_from_the_seed_so_a_different_seed_reframes_the_body() {
+        let uploader = test_uploader();
+        let data = b"hello world";
+        let body_a = uploader.build_streaming_chunks(data, "20260206", "20260206T000000Z", "scope", &"0".repeat(64));
+        let body_b = uploader.build_streaming_chunks(data, "20260206", "20260206T000000Z", "scope", &"1".repeat(64));
+        assert_ne!(body_a, body_b);
+    }
 }
\ No newline at end of file